@@ -0,0 +1,82 @@
+// Copyright 2018-2023 the Deno authors. All rights reserved. MIT license.
+
+use deno_core::error::AnyError;
+use deno_core::url::Url;
+use reqwest::header::HeaderMap;
+use reqwest::header::HeaderName;
+use reqwest::header::HeaderValue;
+use reqwest::Client;
+use reqwest::StatusCode;
+
+use crate::util::progress_bar::UpdateGuard;
+
+/// A response from the registry that surfaces the status code and headers
+/// alongside the body so callers can implement conditional requests (e.g.
+/// `ETag`/`If-None-Match` revalidation). `bytes` is empty for a
+/// `304 Not Modified` response.
+#[derive(Debug)]
+pub struct HttpResponse {
+  pub status: StatusCode,
+  pub headers: HeaderMap,
+  pub bytes: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpClient {
+  client: Client,
+}
+
+impl HttpClient {
+  pub fn new(client: Client) -> Self {
+    Self { client }
+  }
+
+  /// Downloads a url, optionally sending a single request header, returning the
+  /// body bytes or `None` when the resource doesn't exist (404).
+  pub async fn download_with_progress(
+    &self,
+    url: Url,
+    maybe_header: Option<(HeaderName, HeaderValue)>,
+    progress_guard: &UpdateGuard,
+  ) -> Result<Option<Vec<u8>>, AnyError> {
+    let mut headers = HeaderMap::new();
+    if let Some((name, value)) = maybe_header {
+      headers.insert(name, value);
+    }
+    Ok(
+      self
+        .download_with_progress_and_headers(url, headers, progress_guard)
+        .await?
+        .map(|response| response.bytes),
+    )
+  }
+
+  /// Like [`HttpClient::download_with_progress`] but sends arbitrary request
+  /// headers and surfaces the response status and headers. Returns `None` when
+  /// the resource doesn't exist (404); a `304 Not Modified` response comes back
+  /// as `Some` with an empty body so the caller can keep its cached copy.
+  pub async fn download_with_progress_and_headers(
+    &self,
+    url: Url,
+    headers: HeaderMap,
+    _progress_guard: &UpdateGuard,
+  ) -> Result<Option<HttpResponse>, AnyError> {
+    let response = self.client.get(url).headers(headers).send().await?;
+    let status = response.status();
+    if status == StatusCode::NOT_FOUND {
+      return Ok(None);
+    }
+    let headers = response.headers().clone();
+    let bytes = if status == StatusCode::NOT_MODIFIED {
+      Vec::new()
+    } else {
+      response.error_for_status_ref()?;
+      response.bytes().await?.to_vec()
+    };
+    Ok(Some(HttpResponse {
+      status,
+      headers,
+      bytes,
+    }))
+  }
+}
@@ -4,6 +4,7 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fs;
 use std::io::ErrorKind;
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -21,7 +22,17 @@ use deno_core::url::Url;
 use deno_core::TaskQueue;
 use deno_npm::registry::NpmPackageInfo;
 use deno_npm::registry::NpmRegistryApi;
+use deno_semver::Version;
 use once_cell::sync::Lazy;
+use reqwest::header::HeaderMap;
+use reqwest::header::HeaderValue;
+use reqwest::header::ACCEPT;
+use reqwest::header::AUTHORIZATION;
+use reqwest::header::ETAG;
+use reqwest::header::IF_MODIFIED_SINCE;
+use reqwest::header::IF_NONE_MATCH;
+use reqwest::header::LAST_MODIFIED;
+use reqwest::StatusCode;
 
 use crate::args::CacheSetting;
 use crate::cache::CACHE_PERM;
@@ -50,6 +61,191 @@ static NPM_REGISTRY_DEFAULT_URL: Lazy<Url> = Lazy::new(|| {
   Url::parse("https://registry.npmjs.org").unwrap()
 });
 
+/// `Accept` value that asks the registry for the smaller "abbreviated"
+/// metadata document (name, dist-tags, and per-version
+/// `dependencies`/`dist`/`engines`/etc.) instead of the full document that
+/// also carries READMEs, maintainer lists, and other bulk we never read.
+const NPM_ABBREVIATED_METADATA_ACCEPT: &str =
+  "application/vnd.npm.install-v1+json";
+
+/// `.npmrc`-style configuration mapping package scopes to registries and the
+/// credentials to use for each. Unscoped packages and scopes without an
+/// explicit override fall back to [`NpmRc::default_registry`] (or, when that is
+/// unset, to the globally configured registry URL).
+#[derive(Debug, Default, Clone)]
+pub struct NpmRc {
+  /// Registry to use for a given scope, keyed by the scope name *without* the
+  /// leading `@` (e.g. `myorg` for `@myorg:registry=...`).
+  scope_registries: HashMap<String, RegistryConfig>,
+  /// Registry used when no scope-specific entry matches.
+  default_registry: Option<RegistryConfig>,
+  /// Credentials keyed by registry URL (see [`registry_url_key`]). Retained so
+  /// auth can also be attached to the globally-configured `base_url` registry,
+  /// which has no `RegistryConfig` of its own.
+  registry_auth: HashMap<String, RegistryAuth>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RegistryConfig {
+  pub base_url: Url,
+  pub auth: Option<RegistryAuth>,
+}
+
+/// Credentials attached to a registry as an `Authorization` header.
+#[derive(Debug, Clone)]
+pub enum RegistryAuth {
+  /// `_authToken=...` — sent as `Bearer <token>`.
+  Bearer(String),
+  /// `_auth=...` (base64 of `user:password`) — sent as `Basic <value>`.
+  Basic(String),
+}
+
+impl RegistryAuth {
+  fn header_value(&self) -> Option<HeaderValue> {
+    let raw = match self {
+      RegistryAuth::Bearer(token) => format!("Bearer {token}"),
+      RegistryAuth::Basic(value) => format!("Basic {value}"),
+    };
+    HeaderValue::try_from(raw).ok()
+  }
+}
+
+/// The registry (and any credentials) resolved for a particular package.
+struct ResolvedRegistry {
+  base_url: Url,
+  auth: Option<RegistryAuth>,
+}
+
+impl NpmRc {
+  /// Loads and merges npm configuration from the user's home `.npmrc` and an
+  /// optional project-level `.npmrc`, with the project file taking precedence.
+  /// Returns an empty config (the global default registry, no credentials)
+  /// when neither file exists.
+  ///
+  /// The result is the `npmrc` value that [`NpmRegistry::new`] takes.
+  pub fn load(maybe_project_dir: Option<&Path>) -> Self {
+    let mut text = String::new();
+    if let Some(home_npmrc) = home_npmrc_path() {
+      if let Ok(contents) = fs::read_to_string(home_npmrc) {
+        text.push_str(&contents);
+        text.push('\n');
+      }
+    }
+    // appended last so project entries overwrite the home ones on conflict
+    if let Some(project_dir) = maybe_project_dir {
+      if let Ok(contents) = fs::read_to_string(project_dir.join(".npmrc")) {
+        text.push_str(&contents);
+        text.push('\n');
+      }
+    }
+    NpmRc::parse(&text)
+  }
+
+  /// Parses the subset of the `.npmrc` format we care about: `registry`,
+  /// `@scope:registry`, and the `_authToken`/`_auth` credential entries keyed
+  /// by a registry URL.
+  pub fn parse(text: &str) -> Self {
+    let mut default_url: Option<Url> = None;
+    let mut scope_urls: HashMap<String, Url> = HashMap::new();
+    let mut auth_tokens: HashMap<String, String> = HashMap::new();
+    let mut basic_auth: HashMap<String, String> = HashMap::new();
+
+    for line in text.lines() {
+      let line = line.trim();
+      if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+        continue;
+      }
+      let Some((key, value)) = line.split_once('=') else {
+        continue;
+      };
+      let (key, value) = (key.trim(), value.trim());
+      if key == "registry" {
+        if let Some(url) = parse_registry_url(value) {
+          default_url = Some(url);
+        }
+      } else if let Some(scope) = key
+        .strip_prefix('@')
+        .and_then(|key| key.strip_suffix(":registry"))
+      {
+        if let Some(url) = parse_registry_url(value) {
+          scope_urls.insert(scope.to_string(), url);
+        }
+      } else if let Some(registry_key) = key.strip_suffix(":_authToken") {
+        auth_tokens
+          .insert(normalize_registry_key(registry_key), value.to_string());
+      } else if let Some(registry_key) = key.strip_suffix(":_auth") {
+        basic_auth
+          .insert(normalize_registry_key(registry_key), value.to_string());
+      }
+    }
+
+    // merge the credential entries into one map keyed by registry URL, with
+    // `_authToken` taking precedence over `_auth` for the same registry
+    let mut registry_auth: HashMap<String, RegistryAuth> = HashMap::new();
+    for (key, value) in basic_auth {
+      registry_auth.insert(key, RegistryAuth::Basic(value));
+    }
+    for (key, value) in auth_tokens {
+      registry_auth.insert(key, RegistryAuth::Bearer(value));
+    }
+
+    let to_config = |url: Url| RegistryConfig {
+      auth: registry_auth.get(&registry_url_key(&url)).cloned(),
+      base_url: url,
+    };
+
+    NpmRc {
+      scope_registries: scope_urls
+        .into_iter()
+        .map(|(scope, url)| (scope, to_config(url)))
+        .collect(),
+      default_registry: default_url.map(to_config),
+      registry_auth,
+    }
+  }
+
+  /// Credentials configured for the given registry URL, if any. Used to attach
+  /// auth to the globally-configured `base_url` registry, which has no
+  /// scope/`registry=` entry of its own.
+  fn auth_for(&self, url: &Url) -> Option<RegistryAuth> {
+    self.registry_auth.get(&registry_url_key(url)).cloned()
+  }
+}
+
+/// Returns the scope of a package name without the leading `@`, e.g.
+/// `@myorg/pkg` -> `Some("myorg")`; unscoped names return `None`.
+fn package_scope(name: &str) -> Option<&str> {
+  name.strip_prefix('@').and_then(|rest| rest.split('/').next())
+}
+
+/// Parses a registry URL from an `.npmrc` value, ensuring a trailing slash so
+/// it behaves as a directory when joined with a package name.
+fn parse_registry_url(value: &str) -> Option<Url> {
+  let value = format!("{}/", value.trim_end_matches('/'));
+  Url::parse(&value).ok()
+}
+
+/// Normalizes an `.npmrc` credential key (e.g. `//npm.company.com/`) to the
+/// same host/port/path form produced by [`registry_url_key`] so credentials
+/// can be matched to a registry URL.
+fn normalize_registry_key(key: &str) -> String {
+  key.trim_start_matches("//").trim_end_matches('/').to_string()
+}
+
+/// Path to the user-level `.npmrc` in the home directory, if it can be located.
+fn home_npmrc_path() -> Option<PathBuf> {
+  std::env::var_os("HOME")
+    .or_else(|| std::env::var_os("USERPROFILE"))
+    .map(|home| PathBuf::from(home).join(".npmrc"))
+}
+
+fn registry_url_key(url: &Url) -> String {
+  let host = url.host_str().unwrap_or("");
+  let port = url.port().map(|p| format!(":{p}")).unwrap_or_default();
+  let path = url.path().trim_end_matches('/');
+  format!("{host}{port}{path}")
+}
+
 #[derive(Clone, Debug)]
 pub struct NpmRegistry(Option<Arc<NpmRegistryApiInner>>);
 
@@ -60,17 +256,37 @@ impl NpmRegistry {
 
   pub fn new(
     base_url: Url,
+    npmrc: NpmRc,
     cache: NpmCache,
     http_client: HttpClient,
     progress_bar: ProgressBar,
+  ) -> Self {
+    // default to the full document to preserve the existing on-disk cache
+    // layout (`registry.json`); callers opt into abbreviated metadata via
+    // `with_metadata`, which caches to a separate file
+    Self::with_metadata(base_url, npmrc, cache, http_client, progress_bar, false)
+  }
+
+  /// Like [`NpmRegistry::new`] but lets the caller choose whether to request
+  /// the registry's "abbreviated" install metadata or the full document.
+  pub fn with_metadata(
+    base_url: Url,
+    npmrc: NpmRc,
+    cache: NpmCache,
+    http_client: HttpClient,
+    progress_bar: ProgressBar,
+    abbreviated_metadata: bool,
   ) -> Self {
     Self(Some(Arc::new(NpmRegistryApiInner {
       base_url,
+      npmrc,
       cache,
       mem_cache: Default::default(),
       previously_reloaded_packages: Default::default(),
       http_client,
       progress_bar,
+      abbreviated_metadata,
+      pinned_versions: Default::default(),
     })))
   }
 
@@ -92,6 +308,21 @@ impl NpmRegistry {
     self.inner().get_cached_package_info(name)
   }
 
+  /// Registers the exact versions a lockfile pins for each package, keyed by
+  /// package name. When a package is later resolved via
+  /// [`NpmRegistryApi::maybe_package_info`] and served from the file cache, only
+  /// these versions are parsed out of the `registry.cache` companion instead of
+  /// deserializing the whole `registry.json`.
+  ///
+  /// The registry does not register any versions on its own: until this is
+  /// called, every file-cache load parses the whole `registry.json`.
+  pub fn set_pinned_versions(
+    &self,
+    pinned_versions: HashMap<String, Vec<Version>>,
+  ) {
+    *self.inner().pinned_versions.lock() = pinned_versions;
+  }
+
   pub fn base_url(&self) -> &Url {
     &self.inner().base_url
   }
@@ -131,14 +362,29 @@ enum CacheItem {
   Resolved(Option<Arc<NpmPackageInfo>>),
 }
 
+/// Cached response validators used to revalidate `registry.json` with a
+/// conditional request instead of re-downloading the whole document.
+#[derive(Debug)]
+struct CachedHeaders {
+  etag: Option<String>,
+  last_modified: Option<String>,
+}
+
 #[derive(Debug)]
 struct NpmRegistryApiInner {
   base_url: Url,
+  npmrc: NpmRc,
   cache: NpmCache,
   mem_cache: Mutex<HashMap<String, CacheItem>>,
   previously_reloaded_packages: Mutex<HashSet<String>>,
   http_client: HttpClient,
   progress_bar: ProgressBar,
+  /// When set, request the registry's smaller "abbreviated" install metadata
+  /// and cache it separately from the full document.
+  abbreviated_metadata: bool,
+  /// Versions a lockfile pins per package, used to read only those entries out
+  /// of the companion cache instead of parsing the whole document.
+  pinned_versions: Mutex<HashMap<String, Vec<Version>>>,
 }
 
 impl NpmRegistryApiInner {
@@ -159,8 +405,9 @@ impl NpmRegistryApiInner {
         // file system cache
         || !self.previously_reloaded_packages.lock().insert(name.to_string())
           {
-            // attempt to load from the file cache
-            if let Some(info) = self.load_file_cached_package_info(name) {
+            // attempt to load from the file cache, parsing only the pinned
+            // versions out of the companion when a lockfile registered them
+            if let Some(info) = self.load_file_cached_info(name) {
               let result = Some(Arc::new(info));
               mem_cache
                 .insert(name.to_string(), CacheItem::Resolved(result.clone()));
@@ -203,6 +450,41 @@ impl NpmRegistryApiInner {
     }
   }
 
+  /// Loads a package from the file cache, going through the read-optimized
+  /// companion (parsing only the lockfile-pinned versions) when those versions
+  /// are known, and otherwise parsing the whole `registry.json`.
+  fn load_file_cached_info(&self, name: &str) -> Option<NpmPackageInfo> {
+    if let Some(versions) = self.pinned_versions_for(name) {
+      return self.load_file_cached_versions_or_panic(name, &versions);
+    }
+    self.load_file_cached_package_info(name)
+  }
+
+  fn pinned_versions_for(&self, name: &str) -> Option<Vec<Version>> {
+    let pinned_versions = self.pinned_versions.lock();
+    pinned_versions
+      .get(name)
+      .filter(|versions| !versions.is_empty())
+      .cloned()
+  }
+
+  fn load_file_cached_versions_or_panic(
+    &self,
+    name: &str,
+    versions: &[Version],
+  ) -> Option<NpmPackageInfo> {
+    match self.load_file_cached_versions(name, versions) {
+      Ok(value) => value,
+      Err(err) => {
+        if cfg!(debug_assertions) {
+          panic!("error loading cached npm versions for {name}: {err:#}");
+        } else {
+          None
+        }
+      }
+    }
+  }
+
   fn load_file_cached_package_info(
     &self,
     name: &str,
@@ -267,10 +549,143 @@ impl NpmRegistryApiInner {
     let file_cache_path = self.get_package_file_cache_path(name);
     let file_text = serde_json::to_string(&package_info)?;
     std::fs::create_dir_all(file_cache_path.parent().unwrap())?;
-    atomic_write_file(&file_cache_path, file_text, CACHE_PERM)?;
+    atomic_write_file(&file_cache_path, &file_text, CACHE_PERM)?;
+    // write a companion file that lets us later deserialize only the
+    // versions we need rather than re-parsing the whole document
+    if let Ok(cache_bytes) = build_registry_cache(&file_text, package_info) {
+      let cache_path = self.get_package_cache_file_cache_path(name);
+      atomic_write_file(&cache_path, cache_bytes, CACHE_PERM)?;
+    }
+    Ok(())
+  }
+
+  /// Persists the validators (`ETag`/`Last-Modified`) from a registry response
+  /// in a sidecar so later fetches can revalidate with a conditional request.
+  fn save_package_headers_to_file_cache(
+    &self,
+    name: &str,
+    headers: &HeaderMap,
+  ) {
+    let header_str = |name: reqwest::header::HeaderName| {
+      headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string())
+    };
+    let cached = CachedHeaders {
+      etag: header_str(ETAG),
+      last_modified: header_str(LAST_MODIFIED),
+    };
+    if cached.etag.is_none() && cached.last_modified.is_none() {
+      return;
+    }
+    if let Err(err) = self.save_package_headers_to_file_cache_result(name, &cached)
+    {
+      if cfg!(debug_assertions) {
+        panic!("error saving cached npm headers for {name}: {err:#}");
+      }
+    }
+  }
+
+  fn save_package_headers_to_file_cache_result(
+    &self,
+    name: &str,
+    cached: &CachedHeaders,
+  ) -> Result<(), AnyError> {
+    let path = self.get_package_headers_file_cache_path(name);
+    let mut obj = serde_json::Map::new();
+    if let Some(etag) = &cached.etag {
+      obj.insert("etag".to_string(), etag.clone().into());
+    }
+    if let Some(last_modified) = &cached.last_modified {
+      obj.insert("last_modified".to_string(), last_modified.clone().into());
+    }
+    let file_text = serde_json::to_string(&serde_json::Value::Object(obj))?;
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    atomic_write_file(&path, file_text, CACHE_PERM)?;
     Ok(())
   }
 
+  fn load_cached_headers(&self, name: &str) -> Option<CachedHeaders> {
+    let path = self.get_package_headers_file_cache_path(name);
+    let file_text = fs::read_to_string(path).ok()?;
+    let value: serde_json::Value = serde_json::from_str(&file_text).ok()?;
+    let as_string = |key: &str| {
+      value
+        .get(key)
+        .and_then(|value| value.as_str())
+        .map(|value| value.to_string())
+    };
+    Some(CachedHeaders {
+      etag: as_string("etag"),
+      last_modified: as_string("last_modified"),
+    })
+  }
+
+  /// Rewrites the cached document in place with its own bytes so its modified
+  /// time is bumped without re-downloading.
+  fn refresh_file_cache_freshness(&self, name: &str) {
+    let path = self.get_package_file_cache_path(name);
+    if let Ok(bytes) = fs::read(&path) {
+      let _ = atomic_write_file(&path, bytes, CACHE_PERM);
+    }
+  }
+
+  /// Loads only the requested `versions` out of the cached `registry.json`
+  /// by consulting the read-optimized `registry.cache` companion file.
+  ///
+  /// Popular packages hold thousands of versions, so parsing the whole
+  /// document just to read the handful pinned by a lockfile is wasteful.
+  /// The companion file stores the non-version metadata alongside a table
+  /// mapping each version to a byte range in a blob of the original
+  /// per-version JSON, so only the requested entries are deserialized.
+  ///
+  /// Falls back to a full parse (and rebuilds the companion file) when the
+  /// header is missing/stale or a requested version isn't present.
+  fn load_file_cached_versions(
+    &self,
+    name: &str,
+    versions: &[Version],
+  ) -> Result<Option<NpmPackageInfo>, AnyError> {
+    let registry_path = self.get_package_file_cache_path(name);
+    let source_text = match fs::read_to_string(&registry_path) {
+      Ok(source_text) => source_text,
+      Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+      Err(err) => return Err(err.into()),
+    };
+
+    let cache_path = self.get_package_cache_file_cache_path(name);
+    match fs::read(&cache_path) {
+      Ok(cache_bytes) => {
+        if let Some(info) =
+          read_registry_cache_versions(&cache_bytes, &source_text, versions)
+        {
+          return Ok(Some(info));
+        }
+      }
+      Err(err) if err.kind() == ErrorKind::NotFound => {}
+      Err(err) => return Err(err.into()),
+    }
+
+    // header missing/stale or a requested version was absent: fall back to a
+    // full parse and rebuild the companion file for next time
+    let package_info: NpmPackageInfo = match serde_json::from_str(&source_text) {
+      Ok(package_info) => package_info,
+      Err(err) => {
+        log::debug!(
+          "error deserializing registry.json for '{}'. Reloading. {:?}",
+          name,
+          err
+        );
+        return Ok(None);
+      }
+    };
+    if let Ok(cache_bytes) = build_registry_cache(&source_text, &package_info) {
+      let _ = atomic_write_file(&cache_path, cache_bytes, CACHE_PERM);
+    }
+    Ok(Some(package_info))
+  }
+
   async fn load_package_info_from_registry(
     &self,
     name: &str,
@@ -303,30 +718,178 @@ impl NpmRegistryApiInner {
       ));
     }
 
-    let package_url = self.get_package_url(name);
-    let guard = self.progress_bar.update(package_url.as_str());
+    let registry = self.registry_config_for_package(name);
+    let package_url = registry.base_url.join(name).unwrap();
+
+    let mut headers = self.base_request_headers(&registry);
+    // revalidate against what we already have on disk so the registry can
+    // answer `304 Not Modified` instead of resending the whole document
+    if let Some(cached) = self.load_cached_headers(name) {
+      if let Some(etag) = cached.etag.and_then(|e| HeaderValue::try_from(e).ok())
+      {
+        headers.insert(IF_NONE_MATCH, etag);
+      }
+      if let Some(last_modified) = cached
+        .last_modified
+        .and_then(|l| HeaderValue::try_from(l).ok())
+      {
+        headers.insert(IF_MODIFIED_SINCE, last_modified);
+      }
+    }
 
-    let maybe_bytes = self
+    let guard = self.progress_bar.update(package_url.as_str());
+    let maybe_response = self
       .http_client
-      .download_with_progress(package_url, &guard)
+      .download_with_progress_and_headers(package_url.clone(), headers, &guard)
       .await?;
-    match maybe_bytes {
-      Some(bytes) => {
-        let package_info = serde_json::from_slice(&bytes)?;
+    match maybe_response {
+      // the package doesn't exist
+      None => Ok(None),
+      // nothing changed since we last fetched: keep the cached document and
+      // just bump its modified time instead of re-downloading it
+      Some(response) if response.status == StatusCode::NOT_MODIFIED => {
+        match self.load_file_cached_package_info(name) {
+          Some(package_info) => {
+            self.refresh_file_cache_freshness(name);
+            Ok(Some(package_info))
+          }
+          // the validators outlived the body cache (e.g. it was evicted): a
+          // 304 would otherwise look like "package missing", so re-download
+          // the full document unconditionally instead
+          None => {
+            self
+              .download_package_info(name, &registry, &package_url)
+              .await
+          }
+        }
+      }
+      Some(response) => {
+        let package_info = serde_json::from_slice(&response.bytes)?;
         self.save_package_info_to_file_cache(name, &package_info);
+        self.save_package_headers_to_file_cache(name, &response.headers);
         Ok(Some(package_info))
       }
+    }
+  }
+
+  /// Unconditionally downloads, caches, and returns a package document (no
+  /// `If-None-Match`/`If-Modified-Since`), used both for first fetches and to
+  /// recover when a `304` arrives but the cached body is gone.
+  async fn download_package_info(
+    &self,
+    name: &str,
+    registry: &ResolvedRegistry,
+    package_url: &Url,
+  ) -> Result<Option<NpmPackageInfo>, AnyError> {
+    let headers = self.base_request_headers(registry);
+    let guard = self.progress_bar.update(package_url.as_str());
+    let maybe_response = self
+      .http_client
+      .download_with_progress_and_headers(package_url.clone(), headers, &guard)
+      .await?;
+    match maybe_response {
       None => Ok(None),
+      Some(response) => {
+        // `NpmPackageInfo`'s full-document-only fields are all optional, so the
+        // abbreviated document deserializes into the same type (see the
+        // `deserializes_abbreviated_metadata` test). A parse failure here is
+        // returned to the caller as an error.
+        let package_info = serde_json::from_slice(&response.bytes)?;
+        self.save_package_info_to_file_cache(name, &package_info);
+        self.save_package_headers_to_file_cache(name, &response.headers);
+        Ok(Some(package_info))
+      }
+    }
+  }
+
+  /// Builds the request headers common to every registry fetch: per-registry
+  /// credentials and, when enabled, the abbreviated-metadata `Accept` header.
+  fn base_request_headers(&self, registry: &ResolvedRegistry) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    // attach per-registry credentials for private/scoped packages
+    if let Some(auth) =
+      registry.auth.as_ref().and_then(RegistryAuth::header_value)
+    {
+      headers.insert(AUTHORIZATION, auth);
+    }
+    // ask for the smaller abbreviated document when enabled
+    if self.abbreviated_metadata {
+      headers.insert(
+        ACCEPT,
+        HeaderValue::from_static(NPM_ABBREVIATED_METADATA_ACCEPT),
+      );
+    }
+    headers
+  }
+
+  /// Resolves the registry a package should be fetched from based on its
+  /// scope, falling back to the default registry when there's no override.
+  fn registry_config_for_package(&self, name: &str) -> ResolvedRegistry {
+    if let Some(scope) = package_scope(name) {
+      if let Some(config) = self.npmrc.scope_registries.get(scope) {
+        return ResolvedRegistry {
+          base_url: config.base_url.clone(),
+          auth: config.auth.clone(),
+        };
+      }
+    }
+    match &self.npmrc.default_registry {
+      Some(config) => ResolvedRegistry {
+        base_url: config.base_url.clone(),
+        auth: config.auth.clone(),
+      },
+      // the globally-configured registry: still attach credentials when the
+      // `.npmrc` has a bare `//host/:_authToken=...` entry matching it
+      None => ResolvedRegistry {
+        auth: self.npmrc.auth_for(&self.base_url),
+        base_url: self.base_url.clone(),
+      },
     }
   }
 
   fn get_package_url(&self, name: &str) -> Url {
-    self.base_url.join(name).unwrap()
+    self
+      .registry_config_for_package(name)
+      .base_url
+      .join(name)
+      .unwrap()
   }
 
   fn get_package_file_cache_path(&self, name: &str) -> PathBuf {
-    let name_folder_path = self.cache.package_name_folder(name, &self.base_url);
-    name_folder_path.join("registry.json")
+    // key the cache off the resolving registry so files from different
+    // registries for the same package name never collide
+    let base_url = self.registry_config_for_package(name).base_url;
+    let name_folder_path = self.cache.package_name_folder(name, &base_url);
+    // keep abbreviated and full metadata in separate files so their differing
+    // shapes never get mixed up in the cache
+    let file_name = if self.abbreviated_metadata {
+      "registry.abbr.json"
+    } else {
+      "registry.json"
+    };
+    name_folder_path.join(file_name)
+  }
+
+  fn get_package_cache_file_cache_path(&self, name: &str) -> PathBuf {
+    let base_url = self.registry_config_for_package(name).base_url;
+    let name_folder_path = self.cache.package_name_folder(name, &base_url);
+    let file_name = if self.abbreviated_metadata {
+      "registry.abbr.cache"
+    } else {
+      "registry.cache"
+    };
+    name_folder_path.join(file_name)
+  }
+
+  fn get_package_headers_file_cache_path(&self, name: &str) -> PathBuf {
+    let base_url = self.registry_config_for_package(name).base_url;
+    let name_folder_path = self.cache.package_name_folder(name, &base_url);
+    let file_name = if self.abbreviated_metadata {
+      "registry.abbr.headers"
+    } else {
+      "registry.headers"
+    };
+    name_folder_path.join(file_name)
   }
 
   pub fn clear_memory_cache(&self) {
@@ -345,3 +908,315 @@ impl NpmRegistryApiInner {
     }
   }
 }
+
+/// Magic number identifying the read-optimized registry companion file: "NPMC"
+/// read as a big-endian `u32`, so the file (written little-endian) starts with
+/// the bytes "CMPN".
+const REGISTRY_CACHE_MAGIC: u32 = 0x4e50_4d43;
+/// Bumped whenever the companion file layout changes so stale files are ignored.
+const REGISTRY_CACHE_FORMAT_VERSION: u32 = 1;
+
+/// Builds the `registry.cache` companion for a freshly saved `registry.json`.
+///
+/// Layout (all integers little-endian): magic, format version, a hash and the
+/// byte length of the source `registry.json` used to detect staleness, the
+/// non-version metadata as JSON, a table of `(version, offset, length)` entries,
+/// and finally the blob of per-version JSON the offsets point into.
+fn build_registry_cache(
+  source_text: &str,
+  package_info: &NpmPackageInfo,
+) -> Result<Vec<u8>, AnyError> {
+  let mut doc = serde_json::to_value(package_info)?;
+  let versions = match doc.as_object_mut().and_then(|o| o.remove("versions")) {
+    Some(serde_json::Value::Object(versions)) => versions,
+    _ => Default::default(),
+  };
+  let meta_bytes = serde_json::to_vec(&doc)?;
+
+  let mut table = Vec::with_capacity(versions.len());
+  let mut blob = Vec::new();
+  for (version, value) in &versions {
+    let offset = blob.len();
+    serde_json::to_writer(&mut blob, value)?;
+    table.push((version.clone(), offset, blob.len() - offset));
+  }
+
+  let mut out = Vec::new();
+  out.extend_from_slice(&REGISTRY_CACHE_MAGIC.to_le_bytes());
+  out.extend_from_slice(&REGISTRY_CACHE_FORMAT_VERSION.to_le_bytes());
+  out.extend_from_slice(&hash_registry_source(source_text).to_le_bytes());
+  out.extend_from_slice(&(source_text.len() as u64).to_le_bytes());
+  out.extend_from_slice(&(meta_bytes.len() as u32).to_le_bytes());
+  out.extend_from_slice(&meta_bytes);
+  out.extend_from_slice(&(table.len() as u32).to_le_bytes());
+  for (version, offset, len) in &table {
+    out.extend_from_slice(&(version.len() as u32).to_le_bytes());
+    out.extend_from_slice(version.as_bytes());
+    out.extend_from_slice(&(*offset as u64).to_le_bytes());
+    out.extend_from_slice(&(*len as u64).to_le_bytes());
+  }
+  out.extend_from_slice(&blob);
+  Ok(out)
+}
+
+/// Reads just `versions` out of the companion file, returning `None` (so the
+/// caller falls back to a full parse) when the header is missing/stale or any
+/// requested version isn't present.
+fn read_registry_cache_versions(
+  bytes: &[u8],
+  source_text: &str,
+  versions: &[Version],
+) -> Option<NpmPackageInfo> {
+  let mut reader = RegistryCacheReader::new(bytes);
+  if reader.read_u32()? != REGISTRY_CACHE_MAGIC
+    || reader.read_u32()? != REGISTRY_CACHE_FORMAT_VERSION
+  {
+    return None;
+  }
+  let stored_hash = reader.read_u64()?;
+  let stored_len = reader.read_u64()?;
+  if stored_len != source_text.len() as u64
+    || stored_hash != hash_registry_source(source_text)
+  {
+    return None; // stale relative to the current registry.json
+  }
+
+  let meta_len = reader.read_u32()? as usize;
+  let meta: serde_json::Value =
+    serde_json::from_slice(reader.read_bytes(meta_len)?).ok()?;
+
+  let entry_count = reader.read_u32()? as usize;
+  let mut offsets = HashMap::with_capacity(entry_count);
+  for _ in 0..entry_count {
+    let name_len = reader.read_u32()? as usize;
+    let version = std::str::from_utf8(reader.read_bytes(name_len)?)
+      .ok()?
+      .to_string();
+    let offset = reader.read_u64()? as usize;
+    let len = reader.read_u64()? as usize;
+    offsets.insert(version, (offset, len));
+  }
+  let blob = reader.remaining();
+
+  let mut selected = serde_json::Map::with_capacity(versions.len());
+  for version in versions {
+    let key = version.to_string();
+    let (offset, len) = *offsets.get(&key)?;
+    let slice = blob.get(offset..offset.checked_add(len)?)?;
+    selected.insert(key, serde_json::from_slice(slice).ok()?);
+  }
+
+  let mut doc = meta;
+  doc
+    .as_object_mut()?
+    .insert("versions".to_string(), serde_json::Value::Object(selected));
+  serde_json::from_value(doc).ok()
+}
+
+fn hash_registry_source(text: &str) -> u64 {
+  use std::hash::Hasher;
+  let mut hasher = std::collections::hash_map::DefaultHasher::new();
+  hasher.write(text.as_bytes());
+  hasher.finish()
+}
+
+/// Minimal forward cursor over the companion file. Every read is bounds-checked
+/// and returns `None` on a short buffer so a truncated file is treated as stale.
+struct RegistryCacheReader<'a> {
+  bytes: &'a [u8],
+  pos: usize,
+}
+
+impl<'a> RegistryCacheReader<'a> {
+  fn new(bytes: &'a [u8]) -> Self {
+    Self { bytes, pos: 0 }
+  }
+
+  fn read_bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+    let slice = self.bytes.get(self.pos..self.pos.checked_add(len)?)?;
+    self.pos += len;
+    Some(slice)
+  }
+
+  fn read_u32(&mut self) -> Option<u32> {
+    Some(u32::from_le_bytes(self.read_bytes(4)?.try_into().unwrap()))
+  }
+
+  fn read_u64(&mut self) -> Option<u64> {
+    Some(u64::from_le_bytes(self.read_bytes(8)?.try_into().unwrap()))
+  }
+
+  fn remaining(&self) -> &'a [u8] {
+    &self.bytes[self.pos..]
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn test_package_info() -> (String, NpmPackageInfo) {
+    let value = serde_json::json!({
+      "name": "foo",
+      "dist-tags": { "latest": "2.0.0" },
+      "versions": {
+        "1.0.0": {
+          "version": "1.0.0",
+          "dist": {
+            "tarball": "https://registry.npmjs.org/foo/-/foo-1.0.0.tgz",
+            "shasum": "aaa",
+            "integrity": "sha512-aaa"
+          }
+        },
+        "2.0.0": {
+          "version": "2.0.0",
+          "dependencies": { "bar": "^1.0.0" },
+          "dist": {
+            "tarball": "https://registry.npmjs.org/foo/-/foo-2.0.0.tgz",
+            "shasum": "bbb",
+            "integrity": "sha512-bbb"
+          }
+        }
+      }
+    });
+    let info: NpmPackageInfo = serde_json::from_value(value).unwrap();
+    let source_text = serde_json::to_string(&info).unwrap();
+    (source_text, info)
+  }
+
+  #[test]
+  fn registry_cache_round_trip() {
+    let (source_text, info) = test_package_info();
+    let cache = build_registry_cache(&source_text, &info).unwrap();
+
+    let version = Version::parse_from_npm("1.0.0").unwrap();
+    let loaded =
+      read_registry_cache_versions(&cache, &source_text, &[version]).unwrap();
+
+    // only the requested version is parsed, but the non-version metadata
+    // survives the round trip
+    assert_eq!(loaded.name, "foo");
+    assert_eq!(loaded.dist_tags.get("latest").unwrap(), "2.0.0");
+    assert_eq!(loaded.versions.len(), 1);
+    assert!(loaded.versions.contains_key("1.0.0"));
+    assert!(!loaded.versions.contains_key("2.0.0"));
+  }
+
+  #[test]
+  fn registry_cache_stale_hash_falls_back() {
+    let (source_text, info) = test_package_info();
+    let cache = build_registry_cache(&source_text, &info).unwrap();
+
+    // the companion no longer matches the (mutated) registry.json
+    let mutated = format!("{source_text} ");
+    let version = Version::parse_from_npm("1.0.0").unwrap();
+    assert!(
+      read_registry_cache_versions(&cache, &mutated, &[version]).is_none()
+    );
+  }
+
+  #[test]
+  fn registry_cache_missing_version_falls_back() {
+    let (source_text, info) = test_package_info();
+    let cache = build_registry_cache(&source_text, &info).unwrap();
+
+    let missing = Version::parse_from_npm("9.9.9").unwrap();
+    assert!(
+      read_registry_cache_versions(&cache, &source_text, &[missing]).is_none()
+    );
+  }
+
+  #[test]
+  fn parses_scope_registries_and_auth() {
+    let npmrc = NpmRc::parse(
+      "
+      registry=https://registry.npmjs.org
+      @myorg:registry=https://npm.mycompany.com
+      //npm.mycompany.com/:_authToken=secret-token
+      //other.example.com/:_auth=dXNlcjpwYXNz
+      # a comment
+      ",
+    );
+
+    let scope = npmrc.scope_registries.get("myorg").unwrap();
+    assert_eq!(scope.base_url.as_str(), "https://npm.mycompany.com/");
+    match scope.auth.as_ref().unwrap() {
+      RegistryAuth::Bearer(token) => assert_eq!(token, "secret-token"),
+      other => panic!("unexpected auth: {other:?}"),
+    }
+
+    let default = npmrc.default_registry.as_ref().unwrap();
+    assert_eq!(default.base_url.as_str(), "https://registry.npmjs.org/");
+    assert!(default.auth.is_none());
+  }
+
+  #[test]
+  fn attaches_auth_to_default_base_url() {
+    // a bare credential entry with no `registry=`/scope line still applies to
+    // the globally-configured registry
+    let npmrc =
+      NpmRc::parse("//private.example.com/:_authToken=default-token");
+    assert!(npmrc.default_registry.is_none());
+
+    let base_url = Url::parse("https://private.example.com/").unwrap();
+    match npmrc.auth_for(&base_url).unwrap() {
+      RegistryAuth::Bearer(token) => assert_eq!(token, "default-token"),
+      other => panic!("unexpected auth: {other:?}"),
+    }
+    assert!(npmrc
+      .auth_for(&Url::parse("https://registry.npmjs.org/").unwrap())
+      .is_none());
+  }
+
+  #[test]
+  fn package_scope_variants() {
+    assert_eq!(package_scope("@myorg/pkg"), Some("myorg"));
+    assert_eq!(package_scope("@myorg"), Some("myorg"));
+    assert_eq!(package_scope("lodash"), None);
+  }
+
+  #[test]
+  fn registry_key_normalization() {
+    let url = Url::parse("https://npm.mycompany.com/").unwrap();
+    assert_eq!(registry_url_key(&url), "npm.mycompany.com");
+    assert_eq!(
+      normalize_registry_key("//npm.mycompany.com/"),
+      registry_url_key(&url)
+    );
+
+    let with_port_and_path =
+      Url::parse("https://npm.mycompany.com:8443/path/").unwrap();
+    assert_eq!(
+      registry_url_key(&with_port_and_path),
+      "npm.mycompany.com:8443/path"
+    );
+  }
+
+  #[test]
+  fn deserializes_abbreviated_metadata() {
+    // a representative `application/vnd.npm.install-v1+json` document: no
+    // README, no maintainer list, only install-relevant fields
+    let abbreviated = serde_json::json!({
+      "name": "foo",
+      "dist-tags": { "latest": "1.0.0" },
+      "modified": "2023-01-01T00:00:00.000Z",
+      "versions": {
+        "1.0.0": {
+          "name": "foo",
+          "version": "1.0.0",
+          "dependencies": { "bar": "^2.0.0" },
+          "dist": {
+            "tarball": "https://registry.npmjs.org/foo/-/foo-1.0.0.tgz",
+            "shasum": "aaa",
+            "integrity": "sha512-aaa"
+          },
+          "engines": { "node": ">=14" }
+        }
+      }
+    });
+    let info: NpmPackageInfo =
+      serde_json::from_value(abbreviated).unwrap();
+    assert_eq!(info.versions.len(), 1);
+    assert!(info.versions.contains_key("1.0.0"));
+  }
+}